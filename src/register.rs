@@ -10,16 +10,18 @@
 //! The provided implementation details of the register access abstraction are used by the corresponding macros
 //! of this crate. It is preferred to use the macros to properly define the registers to be used.
 
+use core::cell::Cell;
 use core::ptr::{read_volatile, write_volatile};
-use core::ops::{BitOr, BitAnd, Not, Shl, Shr};
+use core::ops::{BitOr, BitAnd, Not, Shl, Shr, Deref};
 
 /// This trait is used to describe the register size/length as type specifier. The trait is only implemented for the
 /// internal types **u8**, **u16**, **u32** and **u64** to ensure safe register access sizes with compile time checking
-pub trait RegisterType: 
-    Copy + 
+pub trait RegisterType:
+    Copy +
     Clone +
+    PartialEq +
     BitOr<Output=Self> +
-    BitAnd<Output=Self> + 
+    BitAnd<Output=Self> +
     Not<Output=Self> +
     Shl<Self, Output=Self> +
     Shr<Self, Output=Self> { }
@@ -78,6 +80,14 @@ macro_rules! registerget_impl {
             let val = self.get();
             (val & field.mask) >> field.shift
         }
+
+        /// Read the value of a specific register field, keeping the result already masked and shifted so it can be
+        /// combined with other field values of the same register via `|` before being written back.
+        #[inline]
+        pub fn read_value(&self, field: RegisterField<T>) -> RegisterFieldValue<T> {
+            let val = self.get();
+            RegisterFieldValue::new(field.mask, val & field.mask)
+        }
     )
 }
 
@@ -95,6 +105,18 @@ macro_rules! registerset_impl {
             let val = (value & field.mask) << field.shift;
             self.set(val);
         }
+
+        /// Write the value of a specific register field, rejecting a `value` that does not fit into the field's
+        /// bit width instead of silently corrupting the neighbouring fields the way `write` would.
+        #[inline]
+        pub fn checked_write(&self, field: RegisterField<T>, value: T) -> Result<(), RegisterError> {
+            if !field.fits(value) {
+                return Err(RegisterError::ValueOutOfRange);
+            }
+
+            self.set(value << field.shift);
+            Ok(())
+        }
     )
 }
 
@@ -106,6 +128,13 @@ impl<T: RegisterType> ReadOnly<T> {
 impl<T: RegisterType> WriteOnly<T> {
     registernew_impl!();
     registerset_impl!();
+
+    /// Write an already built [`RegisterFieldValue`] to the register, e.g. the combination of several field
+    /// values produced with `|`. As with `write`, any bits outside of the combined field mask are written as `0`.
+    #[inline]
+    pub fn write_value(&self, value: RegisterFieldValue<T>) {
+        self.set(value.value);
+    }
 }
 
 impl<T: RegisterType> ReadWrite<T> {
@@ -113,22 +142,261 @@ impl<T: RegisterType> ReadWrite<T> {
     registerget_impl!();
     registerset_impl!();
 
+    /// Udate a register field with a given value. `value` is not checked against the field's bit width here -
+    /// a `value` wider than the field still corrupts the neighbouring bits, same as `write`. Use `checked_modify`
+    /// where that guarantee matters.
+    pub fn modify(&self, field: RegisterField<T>, value: T) -> T {
+
+        let old_val = self.get();
+        let new_val = (old_val & !field.mask) | (value << field.shift);
+        self.set(new_val);
+
+        new_val
+    }
+
+    /// Update a register field with a given value, rejecting a `value` that does not fit into the field's bit
+    /// width instead of silently corrupting the neighbouring fields the way `modify` would.
+    pub fn checked_modify(&self, field: RegisterField<T>, value: T) -> Result<T, RegisterError> {
+        if !field.fits(value) {
+            return Err(RegisterError::ValueOutOfRange);
+        }
+
+        Ok(self.modify(field, value))
+    }
+
+    /// Write an already built [`RegisterFieldValue`] to the register, e.g. the combination of several field
+    /// values of this register produced with `|`. Unlike `write_value` on a write-only register this performs a
+    /// single read-modify-write, preserving the bits of every field not covered by `value`.
+    pub fn write_value(&self, value: RegisterFieldValue<T>) -> T {
+        let old_val = self.get();
+        let new_val = (old_val & !value.mask) | value.value;
+        self.set(new_val);
+
+        new_val
+    }
+
+    /// Snapshot the current hardware value of the register into an [`InMemoryRegister`]. The returned copy can be
+    /// read and modified locally, without touching the hardware, and committed back in one go with `set_from`.
+    pub fn extract(&self) -> InMemoryRegister<T> {
+        InMemoryRegister::new(self.get())
+    }
+
+    /// Write the contents of an [`InMemoryRegister`] back to the hardware register with a single volatile store.
+    pub fn set_from(&self, mem: &InMemoryRegister<T>) {
+        self.set(mem.get());
+    }
+
+    /// Read the current contents, let `f` compute the fields to change from a [`RegisterReader`] view of them,
+    /// and write the result back. This is the svd2rust-style ergonomics of reading a "reader" and returning a
+    /// "writer" describing the new contents, e.g. `reg.modify_with(|r| r.field(A, 1) | r.field(B, 2))`, and
+    /// internally amounts to a single `get`, the closure, then a single `set` - avoiding the repeated volatile
+    /// accesses of chained `modify` calls.
+    pub fn modify_with<F>(&self, f: F) -> T
+    where
+        F: FnOnce(RegisterReader<T>) -> RegisterFieldValue<T>,
+    {
+        let old_val = self.get();
+        let written = f(RegisterReader::new(old_val));
+        let new_val = (old_val & !written.mask) | written.value;
+        self.set(new_val);
+
+        new_val
+    }
+}
+
+/// A read-only view over a register's current contents, passed into the closure given to
+/// [`ReadWrite::modify_with`]. Exposes `field` to describe a new value for a field as a [`RegisterFieldValue`],
+/// mirroring [`RegisterField::with_value`], without needing the raw field definition to be in scope twice.
+#[derive(Copy, Clone)]
+pub struct RegisterReader<T: RegisterType> {
+    value: T,
+}
+
+impl<T: RegisterType> RegisterReader<T> {
+    fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// The raw, current contents of the register.
+    #[inline]
+    pub fn get(&self) -> T {
+        self.value
+    }
+
+    /// Read the current value of a specific register field.
+    #[inline]
+    pub fn read(&self, field: RegisterField<T>) -> T {
+        (self.value & field.mask) >> field.shift
+    }
+
+    /// Describe a new value for `field`, to be combined with other field values via `|` and written back by
+    /// the enclosing `modify_with` call.
+    #[inline]
+    pub fn field(&self, field: RegisterField<T>, value: T) -> RegisterFieldValue<T> {
+        field.with_value(value)
+    }
+}
+
+/// An in-memory copy of a register's content, with no volatile pointer behind it. Exposes the same `get`/`set`/
+/// `read`/`write`/`modify` API as [`ReadWrite`], so callers can compose several field edits locally - without
+/// issuing a bus cycle per edit - and commit the accumulated value to hardware in a single volatile store via
+/// [`ReadWrite::set_from`].
+#[derive(Clone)]
+pub struct InMemoryRegister<T: RegisterType> {
+    value: Cell<T>,
+}
+
+impl<T: RegisterType> InMemoryRegister<T> {
+    /// Create a new in-memory register, initialized with the given value.
+    pub const fn new(value: T) -> Self {
+        Self { value: Cell::new(value) }
+    }
+
+    /// Read raw content of the in-memory register.
+    #[inline]
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    /// Write raw content value to the in-memory register.
+    #[inline]
+    pub fn set(&self, value: T) {
+        self.value.set(value);
+    }
+
+    /// Read the value of a specific register field
+    #[inline]
+    pub fn read(&self, field: RegisterField<T>) -> T {
+        (self.get() & field.mask) >> field.shift
+    }
+
+    /// Read the value of a specific register field, keeping the result already masked and shifted so it can be
+    /// combined with other field values of the same register via `|`.
+    #[inline]
+    pub fn read_value(&self, field: RegisterField<T>) -> RegisterFieldValue<T> {
+        RegisterFieldValue::new(field.mask, self.get() & field.mask)
+    }
+
+    /// Write the value of a specific register field
+    #[inline]
+    pub fn write(&self, field: RegisterField<T>, value: T) {
+        let val = (value << field.shift) & field.mask;
+        self.set(val);
+    }
+
+    /// Write an already built [`RegisterFieldValue`], e.g. the combination of several field values produced
+    /// with `|`, preserving the bits of every field not covered by `value`.
+    pub fn write_value(&self, value: RegisterFieldValue<T>) -> T {
+        let old_val = self.get();
+        let new_val = (old_val & !value.mask) | value.value;
+        self.set(new_val);
+
+        new_val
+    }
+
     /// Udate a register field with a given value
     pub fn modify(&self, field: RegisterField<T>, value: T) -> T {
 
         let old_val = self.get();
         let new_val = (old_val & !field.mask) | (value << field.shift);
         self.set(new_val);
-        
+
         new_val
     }
 }
 
+/// Capability of a register access type that knows its documented power-on reset value, borrowed from the
+/// reset-value concept of svd2rust-generated code. Lets drivers return a peripheral to its power-on state
+/// without duplicating the constant at every call site.
+pub trait Resettable<T: RegisterType> {
+    /// The register's documented power-on reset value.
+    fn reset_value(&self) -> T;
+
+    /// Write the reset value back to the register with a single volatile store.
+    fn reset(&self);
+}
+
+/// A [`WriteOnly`] register together with its documented power-on reset value. Derefs to the underlying
+/// [`WriteOnly`] so the full write API remains available.
+#[derive(Clone)]
+pub struct ResettableWriteOnly<T: RegisterType> {
+    inner: WriteOnly<T>,
+    reset_value: T,
+}
+
+impl<T: RegisterType> ResettableWriteOnly<T> {
+    /// Create a new instance of the register access struct, remembering its documented reset value.
+    pub const fn new(addr: u32, reset_value: T) -> Self {
+        Self { inner: WriteOnly::new(addr), reset_value }
+    }
+}
+
+impl<T: RegisterType> Deref for ResettableWriteOnly<T> {
+    type Target = WriteOnly<T>;
+
+    fn deref(&self) -> &WriteOnly<T> {
+        &self.inner
+    }
+}
+
+impl<T: RegisterType> Resettable<T> for ResettableWriteOnly<T> {
+    fn reset_value(&self) -> T {
+        self.reset_value
+    }
+
+    fn reset(&self) {
+        self.inner.set(self.reset_value);
+    }
+}
+
+/// A [`ReadWrite`] register together with its documented power-on reset value. Derefs to the underlying
+/// [`ReadWrite`] so the full read/write/modify API remains available.
+#[derive(Clone)]
+pub struct ResettableReadWrite<T: RegisterType> {
+    inner: ReadWrite<T>,
+    reset_value: T,
+}
+
+impl<T: RegisterType> ResettableReadWrite<T> {
+    /// Create a new instance of the register access struct, remembering its documented reset value.
+    pub const fn new(addr: u32, reset_value: T) -> Self {
+        Self { inner: ReadWrite::new(addr), reset_value }
+    }
+
+    /// Snapshot the documented reset value - rather than the current hardware value - into an
+    /// [`InMemoryRegister`]. This seeds the "write-with-reset-then-modify" pattern: start from the reset value,
+    /// edit a few fields locally, then commit once with [`ReadWrite::set_from`].
+    pub fn extract_reset(&self) -> InMemoryRegister<T> {
+        InMemoryRegister::new(self.reset_value)
+    }
+}
+
+impl<T: RegisterType> Deref for ResettableReadWrite<T> {
+    type Target = ReadWrite<T>;
+
+    fn deref(&self) -> &ReadWrite<T> {
+        &self.inner
+    }
+}
+
+impl<T: RegisterType> Resettable<T> for ResettableReadWrite<T> {
+    fn reset_value(&self) -> T {
+        self.reset_value
+    }
+
+    fn reset(&self) {
+        self.inner.set(self.reset_value);
+    }
+}
+
 /// Definition of a field contained inside of a register. Each field is defined by a mask and the bit shift value
-/// when constructing the field definition the stored mask is already shifted by the shift value
+/// when constructing the field definition the stored mask is already shifted by the shift value. The unshifted
+/// `width_mask` is kept alongside it so a value can be checked against - or truncated to - the field's bit width
+/// without having to shift the stored mask back.
 #[derive(Copy, Clone)]
 pub struct RegisterField<T: RegisterType> {
     mask: T,
+    width_mask: T,
     shift: T,
 }
 
@@ -140,6 +408,7 @@ macro_rules! registerfield_impl {
             pub const fn new(mask: $t, shift: $t) -> RegisterField<$t> {
                 Self {
                     mask: mask << shift,
+                    width_mask: mask,
                     shift: shift,
                 }
             }
@@ -147,4 +416,413 @@ macro_rules! registerfield_impl {
     )*);
 }
 
-registerfield_impl![u8, u16, u32, u64];
\ No newline at end of file
+registerfield_impl![u8, u16, u32, u64];
+
+impl<T: RegisterType> RegisterField<T> {
+    /// The field's mask, already shifted into position.
+    pub const fn mask(&self) -> T {
+        self.mask
+    }
+
+    /// The field's unshifted width mask, i.e. the bits available to a raw value before it is shifted into place.
+    pub const fn width_mask(&self) -> T {
+        self.width_mask
+    }
+
+    /// The field's bit shift.
+    pub const fn shift(&self) -> T {
+        self.shift
+    }
+
+    /// Build a [`RegisterFieldValue`] for this field from a raw, unshifted value. The value is truncated
+    /// to the field width before being shifted into place, so it can never bleed into neighbouring fields.
+    pub fn with_value(&self, value: T) -> RegisterFieldValue<T> {
+        RegisterFieldValue {
+            mask: self.mask,
+            value: (value & self.width_mask) << self.shift,
+        }
+    }
+
+    /// Check whether a raw, unshifted `value` fits into this field's bit width, i.e. whether shifting it
+    /// into place would leave the bits of the neighbouring fields untouched.
+    pub fn fits(&self, value: T) -> bool {
+        (value & self.width_mask) == value
+    }
+}
+
+/// Error returned when an operation on a register field cannot be performed safely.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RegisterError {
+    /// The given value does not fit into the bit width of the targeted field and would corrupt neighbouring
+    /// fields if written unchecked.
+    ValueOutOfRange,
+}
+
+/// An already masked and shifted value for a specific register field, as produced by [`RegisterField::with_value`]
+/// or [`ReadOnly::read_value`]/[`ReadWrite::read_value`]. Values targeting different fields of the same register
+/// can be combined with `|` before being written back with a single `write_value`/`modify_with` call.
+#[derive(Copy, Clone)]
+pub struct RegisterFieldValue<T: RegisterType> {
+    mask: T,
+    value: T,
+}
+
+impl<T: RegisterType> RegisterFieldValue<T> {
+    /// Build a value from an already shifted `mask`/`value` pair. Used by the `read_value` accessors and
+    /// [`register_field_values!`]; prefer those or [`RegisterField::with_value`] over constructing a value directly.
+    pub const fn new(mask: T, value: T) -> Self {
+        Self { mask, value }
+    }
+}
+
+impl<T: RegisterType> BitOr for RegisterFieldValue<T> {
+    type Output = Self;
+
+    /// Combine two field values of the same register, e.g. values of different fields produced via
+    /// `RegisterField::with_value` or named field constants, so they can be written back in one go.
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            mask: self.mask | rhs.mask,
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+/// Declares a module of named, pre-built values for a register field. Each entry becomes a `pub const` of type
+/// [`RegisterFieldValue`], truncated to the field's bit width exactly like [`RegisterField::with_value`] (the
+/// truncation is inlined here rather than calling it, since `with_value` itself can't be `const` for a generic
+/// `T: RegisterType`), so predefined values can be combined with `|` (e.g.
+/// `Mode::OUTPUT | SPEED.with_value(3)`, `SPEED` being another field of the same register) without repeating the
+/// raw field. A named value that does not fit
+/// into the field's bit width fails to compile rather than silently bleeding into the next field.
+///
+/// ```
+/// use ruspiro_register::{register_field_values, register::{RegisterField, InMemoryRegister}};
+///
+/// const MODE: RegisterField<u32> = RegisterField::<u32>::new(0b11, 4);
+/// register_field_values! {
+///     Mode: u32 = MODE => {
+///         INPUT = 0b00,
+///         OUTPUT = 0b01,
+///     }
+/// }
+///
+/// fn main() {
+///     let reg: InMemoryRegister<u32> = InMemoryRegister::new(0);
+///     reg.write_value(Mode::OUTPUT);
+///     assert_eq!(reg.read(MODE), 0b01);
+/// }
+/// ```
+#[macro_export]
+macro_rules! register_field_values {
+    ($modname:ident: $t:ty = $field:expr => { $($name:ident = $value:expr),+ $(,)? }) => {
+        #[allow(non_snake_case)]
+        pub mod $modname {
+            #![allow(unused_imports)]
+            use super::*;
+
+            $(
+                pub const $name: $crate::register::RegisterFieldValue<$t> = $crate::register::RegisterFieldValue::new(
+                    $field.mask(),
+                    ($value & $field.width_mask()) << $field.shift(),
+                );
+
+                #[allow(dead_code)]
+                const _: [(); 0 - !(($value & $field.width_mask()) == $value) as usize] = [];
+            )+
+        }
+    };
+}
+
+/// Declares a peripheral as a contiguous block of registers at a single base address, computing every
+/// register's absolute address from its declared offset instead of scattering hand-computed addresses across
+/// the codebase. Registers must be listed in increasing offset order; any gap between two registers must be
+/// declared explicitly as an `_reserved(size)` entry, and a `const` assertion verifies at compile time that
+/// every offset is consistent with the size of the entry before it. An entry whose access type takes a reset
+/// value (see [`ResettableReadWrite`]/[`ResettableWriteOnly`]) may append `= reset_value`:
+///
+/// ```
+/// use ruspiro_register::{define_register_block, register::{ReadWrite, ResettableReadWrite}};
+///
+/// define_register_block! {
+///     pub struct Gpio {
+///         0x00 => gpfsel0: ReadWrite<u32>,
+///         0x04 => gpfsel1: ReadWrite<u32>,
+///         0x08 => _reserved(4),
+///         0x0C => gppud: ResettableReadWrite<u32> = 0x0000_0000,
+///     }
+/// }
+///
+/// // constructing the block only computes addresses - it never reads or writes memory, so this
+/// // is safe to run even though 0x2020_0000 is not backed by real GPIO registers on this host.
+/// let gpio = Gpio::new(0x2020_0000);
+/// ```
+#[macro_export]
+macro_rules! define_register_block {
+    ($vis:vis struct $name:ident { $($body:tt)* }) => {
+        $crate::__define_register_block_munch! {
+            vis = [$vis];
+            name = [$name];
+            base = [base];
+            fields = [];
+            inits = [];
+            checks = [];
+            prev_end = [0usize];
+            rest = [ $($body)* ];
+        }
+    };
+}
+
+// Internal TT-muncher driving `define_register_block!`: it walks the entry list one at a time, growing the
+// struct fields/constructor initializers for actual registers and a compile time order/size check for every
+// entry (including `_reserved` gaps), until the entry list is empty and the final items are emitted. The `base`
+// parameter name is threaded through as a captured `$base` token from the very first expansion rather than
+// written afresh as a bare `base` identifier in every recursive arm - each recursive macro expansion gets its
+// own hygiene context, so a bare `base` written in one arm does not resolve to one written in another.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_register_block_munch {
+    (
+        vis = [$vis:vis];
+        name = [$name:ident];
+        base = [$base:ident];
+        fields = [ $($f_name:ident : $f_acc:ident<$f_t:ty>),* ];
+        inits = [ $($i_name:ident : $i_expr:expr),* ];
+        checks = [ $($check:tt)* ];
+        prev_end = [$prev_end:expr];
+        rest = [];
+    ) => {
+        $vis struct $name {
+            $( pub $f_name: $f_acc<$f_t> ),*
+        }
+
+        impl $name {
+            /// Create the peripheral block at the given base address, computing every register's absolute
+            /// address from its declared offset.
+            pub const fn new($base: u32) -> Self {
+                Self { $( $i_name: $i_expr ),* }
+            }
+        }
+
+        #[allow(dead_code)]
+        const _: () = { $( $check )* };
+    };
+
+    (
+        vis = [$vis:vis];
+        name = [$name:ident];
+        base = [$base:ident];
+        fields = [ $($f_name:ident : $f_acc:ident<$f_t:ty>),* ];
+        inits = [ $($i_name:ident : $i_expr:expr),* ];
+        checks = [ $($check:tt)* ];
+        prev_end = [$prev_end:expr];
+        rest = [ $offset:expr => $field:ident : $acc:ident<$t:ty> = $reset:expr, $($more:tt)* ];
+    ) => {
+        $crate::__define_register_block_munch! {
+            vis = [$vis];
+            name = [$name];
+            base = [$base];
+            fields = [ $($f_name : $f_acc<$f_t>,)* $field : $acc<$t> ];
+            inits = [ $($i_name : $i_expr,)* $field : $acc::new($base + ($offset as u32), $reset) ];
+            checks = [ $($check)* {
+                assert!(
+                    ($offset as usize) == $prev_end,
+                    "registers must be declared in increasing offset order with no undeclared gap - add an explicit _reserved(size) entry"
+                );
+            } ];
+            prev_end = [ ($offset as usize) + core::mem::size_of::<$t>() ];
+            rest = [ $($more)* ];
+        }
+    };
+
+    (
+        vis = [$vis:vis];
+        name = [$name:ident];
+        base = [$base:ident];
+        fields = [ $($f_name:ident : $f_acc:ident<$f_t:ty>),* ];
+        inits = [ $($i_name:ident : $i_expr:expr),* ];
+        checks = [ $($check:tt)* ];
+        prev_end = [$prev_end:expr];
+        rest = [ $offset:expr => $field:ident : $acc:ident<$t:ty>, $($more:tt)* ];
+    ) => {
+        $crate::__define_register_block_munch! {
+            vis = [$vis];
+            name = [$name];
+            base = [$base];
+            fields = [ $($f_name : $f_acc<$f_t>,)* $field : $acc<$t> ];
+            inits = [ $($i_name : $i_expr,)* $field : $acc::new($base + ($offset as u32)) ];
+            checks = [ $($check)* {
+                assert!(
+                    ($offset as usize) == $prev_end,
+                    "registers must be declared in increasing offset order with no undeclared gap - add an explicit _reserved(size) entry"
+                );
+            } ];
+            prev_end = [ ($offset as usize) + core::mem::size_of::<$t>() ];
+            rest = [ $($more)* ];
+        }
+    };
+
+    (
+        vis = [$vis:vis];
+        name = [$name:ident];
+        base = [$base:ident];
+        fields = [ $($f_name:ident : $f_acc:ident<$f_t:ty>),* ];
+        inits = [ $($i_name:ident : $i_expr:expr),* ];
+        checks = [ $($check:tt)* ];
+        prev_end = [$prev_end:expr];
+        rest = [ $offset:expr => _reserved($size:expr), $($more:tt)* ];
+    ) => {
+        $crate::__define_register_block_munch! {
+            vis = [$vis];
+            name = [$name];
+            base = [$base];
+            fields = [ $($f_name : $f_acc<$f_t>),* ];
+            inits = [ $($i_name : $i_expr),* ];
+            checks = [ $($check)* {
+                assert!(
+                    ($offset as usize) == $prev_end,
+                    "a _reserved gap must start exactly where the previous entry ends"
+                );
+            } ];
+            prev_end = [ ($offset as usize) + ($size as usize) ];
+            rest = [ $($more)* ];
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_value_truncates_to_field_width() {
+        let field: RegisterField<u32> = RegisterField::<u32>::new(0b111, 4); // 3-bit field at bit 4
+        let value = field.with_value(0b1111_1010);
+
+        // only the low 3 bits of the input may survive, shifted into place
+        assert_eq!(value.value, (0b1111_1010 & 0b111) << 4);
+    }
+
+    #[test]
+    fn fits_rejects_values_wider_than_the_field() {
+        let field: RegisterField<u32> = RegisterField::<u32>::new(0b111, 4);
+
+        assert!(field.fits(0b111));
+        assert!(!field.fits(0b1000));
+    }
+
+    // builds a ReadWrite over a real host address truncated to u32 - see the comment on
+    // checked_write_rejects_out_of_range_value below for why this only round-trips on a 32-bit target.
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn in_memory_register_extract_and_set_from_round_trip() {
+        let mut storage: u32 = 0;
+        let reg: ReadWrite<u32> = ReadWrite::new(&mut storage as *mut u32 as u32);
+        let field: RegisterField<u32> = RegisterField::<u32>::new(0b111, 4); // 3-bit field at bit 4
+
+        reg.modify(field, 0b101);
+
+        // extract() snapshots the current hardware value, edits happen locally...
+        let snapshot = reg.extract();
+        assert_eq!(snapshot.read(field), 0b101);
+        snapshot.write(field, 0b010);
+        assert_eq!(storage, 0b101 << 4); // ...and must not touch hardware until committed
+
+        // ...and set_from() commits the local copy back with a single store.
+        reg.set_from(&snapshot);
+        assert_eq!(storage, 0b010 << 4);
+    }
+
+    // these tests build a register on top of a real host address truncated to u32, which only
+    // round-trips on a 32-bit target - the same assumption ReadWrite::new(addr: u32) itself makes.
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn checked_write_rejects_out_of_range_value() {
+        let mut storage: u32 = 0;
+        let reg: ReadWrite<u32> = ReadWrite::new(&mut storage as *mut u32 as u32);
+        let field: RegisterField<u32> = RegisterField::<u32>::new(0b111, 0); // 3-bit field at bit 0
+
+        assert!(reg.checked_write(field, 0b111).is_ok());
+        assert_eq!(storage, 0b111);
+
+        assert_eq!(reg.checked_write(field, 0b1000), Err(RegisterError::ValueOutOfRange));
+        assert_eq!(storage, 0b111); // the rejected write must not have touched the register
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn checked_modify_rejects_out_of_range_value() {
+        let mut storage: u32 = 0b101;
+        let reg: ReadWrite<u32> = ReadWrite::new(&mut storage as *mut u32 as u32);
+        let field: RegisterField<u32> = RegisterField::<u32>::new(0b11, 4);
+
+        assert_eq!(reg.checked_modify(field, 0b11), Ok(0b11_0101));
+        assert_eq!(storage, 0b11_0101);
+
+        assert_eq!(reg.checked_modify(field, 0b100), Err(RegisterError::ValueOutOfRange));
+        assert_eq!(storage, 0b11_0101); // the rejected modify must not have touched the register
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn modify_with_combines_reader_fields_in_a_single_get_and_set() {
+        let mut storage: u32 = 0;
+        let reg: ReadWrite<u32> = ReadWrite::new(&mut storage as *mut u32 as u32);
+        let field_a: RegisterField<u32> = RegisterField::<u32>::new(0b11, 0);
+        let field_b: RegisterField<u32> = RegisterField::<u32>::new(0b11, 4);
+
+        reg.write(field_a, 0b01);
+
+        let new_val = reg.modify_with(|r| {
+            // the reader sees the hardware value from before this call, not a partial write
+            assert_eq!(r.read(field_a), 0b01);
+            r.field(field_a, 0b10) | r.field(field_b, 0b11)
+        });
+
+        assert_eq!(new_val, (0b11 << 4) | 0b10);
+        assert_eq!(storage, (0b11 << 4) | 0b10);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    define_register_block! {
+        struct TestBlock {
+            0x00 => reg_a: ReadWrite<u32>,
+            0x04 => _reserved(4),
+            0x08 => reg_b: ReadWrite<u32>,
+        }
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn register_block_addresses_registers_from_base() {
+        // 3 u32 words cover offsets 0x00, 0x04 (reserved) and 0x08
+        let mut backing = [0u32; 3];
+        let base = backing.as_mut_ptr() as u32;
+        let block = TestBlock::new(base);
+
+        block.reg_a.set(0xAAAA_AAAA);
+        block.reg_b.set(0xBBBB_BBBB);
+
+        assert_eq!(backing[0], 0xAAAA_AAAA);
+        assert_eq!(backing[2], 0xBBBB_BBBB);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn resettable_read_write_reports_and_restores_its_reset_value() {
+        let mut storage: u32 = 0;
+        let reg = ResettableReadWrite::<u32>::new(&mut storage as *mut u32 as u32, 0xCAFE_0000);
+
+        assert_eq!(reg.reset_value(), 0xCAFE_0000);
+
+        // extract_reset() snapshots the documented reset value, not the current hardware value
+        let snapshot = reg.extract_reset();
+        assert_eq!(snapshot.get(), 0xCAFE_0000);
+
+        reg.set(0x1234_5678);
+        assert_eq!(reg.get(), 0x1234_5678);
+
+        reg.reset();
+        assert_eq!(storage, 0xCAFE_0000);
+    }
+}
\ No newline at end of file